@@ -5,6 +5,7 @@ use reqwest::{Body, Client as VanillaClient, IntoUrl, Method, Request, Response}
 use serde::Serialize;
 use std::convert::TryFrom;
 use std::fmt::Display;
+use std::sync::Arc;
 use thiserror::Error;
 
 #[cfg(feature = "middleware")]
@@ -13,16 +14,34 @@ pub use anyhow::Error as MiddlewareError;
 pub use reqwest_middleware::ClientWithMiddleware as MiddlewareClient;
 
 /// Wrapper over reqwest::Client or reqwest_middleware::ClientWithMiddleware
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub enum Client {
-    Vanilla(VanillaClient),
+    Vanilla(VanillaClient, VanillaInit),
     #[cfg(feature = "middleware")]
     Middleware(MiddlewareClient),
 }
 
+impl std::fmt::Debug for Client {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Client::Vanilla(c, init) => f
+                .debug_tuple("Vanilla")
+                .field(c)
+                .field(&format_args!("[{} initialisers]", init.0.len()))
+                .finish(),
+            #[cfg(feature = "middleware")]
+            Client::Middleware(c) => f.debug_tuple("Middleware").field(c).finish(),
+        }
+    }
+}
+
+/// The stack of [`RequestInitialiser`]s attached to a [`Client::Vanilla`] by [`ClientBuilder`].
+#[derive(Clone, Default)]
+pub struct VanillaInit(Arc<Vec<Arc<dyn RequestInitialiser>>>);
+
 impl From<VanillaClient> for Client {
     fn from(value: VanillaClient) -> Self {
-        Client::Vanilla(value)
+        Client::Vanilla(value, VanillaInit::default())
     }
 }
 
@@ -33,6 +52,270 @@ impl From<MiddlewareClient> for Client {
     }
 }
 
+/// Initialises every request produced by a [`Client`], e.g. to attach default headers or
+/// [`Extensions`](http::Extensions). Install one or more via [`ClientBuilder::with_init`].
+///
+/// Implementations must return the same [`RequestBuilder`] variant (`Vanilla`/`Middleware`) they
+/// were given; `init` is always called with the variant matching the [`Client`] it came from, so
+/// this is just a matter of transforming `req` in place with its own builder methods rather than
+/// constructing a new one from scratch. A violation is not treated as a hard error: on a
+/// middleware-enabled [`Client`] the bad output is silently discarded and the unmodified request
+/// is used instead.
+pub trait RequestInitialiser: 'static + Send + Sync {
+    fn init(&self, req: RequestBuilder) -> RequestBuilder;
+}
+
+/// A [`RequestInitialiser`] that inserts a fixed extension value into every request, regardless
+/// of whether the `middleware` feature is enabled.
+#[derive(Clone)]
+pub struct Extension<T>(pub T);
+
+impl<T: Clone + Send + Sync + 'static> RequestInitialiser for Extension<T> {
+    fn init(&self, req: RequestBuilder) -> RequestBuilder {
+        req.with_extension(self.0.clone())
+    }
+}
+
+/// An exponential-backoff-with-jitter retry policy for transient failures, installed on a
+/// request via [`RequestBuilder::with_retry`].
+///
+/// For attempt `n` (starting at 0) the backoff is `min(max_interval, base_interval * 2^n)`,
+/// scaled by a random factor in `[0.5, 1.0]` (full jitter). A `Retry-After` response header is
+/// always honored in place of the computed backoff.
+#[derive(Clone, Copy, Debug)]
+pub struct RetryPolicy {
+    pub max_retries: u32,
+    pub base_interval: std::time::Duration,
+    pub max_interval: std::time::Duration,
+}
+
+impl RetryPolicy {
+    /// A policy with sane defaults (100ms base interval, 30s max interval).
+    pub fn new(max_retries: u32) -> Self {
+        Self {
+            max_retries,
+            base_interval: std::time::Duration::from_millis(100),
+            max_interval: std::time::Duration::from_secs(30),
+        }
+    }
+
+    pub fn base_interval(mut self, base_interval: std::time::Duration) -> Self {
+        self.base_interval = base_interval;
+        self
+    }
+
+    pub fn max_interval(mut self, max_interval: std::time::Duration) -> Self {
+        self.max_interval = max_interval;
+        self
+    }
+
+    fn backoff(&self, attempt: u32) -> std::time::Duration {
+        let exp = self.base_interval.saturating_mul(2u32.checked_pow(attempt).unwrap_or(u32::MAX));
+        let capped = exp.min(self.max_interval);
+        capped.mul_f64(0.5 + rand::random::<f64>() * 0.5)
+    }
+}
+
+fn is_transient_reqwest_error(err: &reqwest::Error) -> bool {
+    err.is_connect() || err.is_timeout()
+}
+
+fn is_transient_status(status: reqwest::StatusCode) -> bool {
+    matches!(status.as_u16(), 408 | 429) || status.is_server_error()
+}
+
+fn retry_after(headers: &HeaderMap) -> Option<std::time::Duration> {
+    let value = headers.get(reqwest::header::RETRY_AFTER)?.to_str().ok()?;
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(std::time::Duration::from_secs(secs));
+    }
+    httpdate::parse_http_date(value)
+        .ok()?
+        .duration_since(std::time::SystemTime::now())
+        .ok()
+}
+
+/// Sleeps for `duration` between retry attempts. Tokio's time driver isn't available on
+/// `wasm32-unknown-unknown`, so that target uses a browser-timer-backed sleep instead.
+async fn sleep(duration: std::time::Duration) {
+    #[cfg(not(target_arch = "wasm32"))]
+    tokio::time::sleep(duration).await;
+    #[cfg(target_arch = "wasm32")]
+    gloo_timers::future::sleep(duration).await;
+}
+
+/// Returns `Some(delay)` if `result` is a transient failure that `policy` allows retrying at
+/// `attempt`, or `None` if `result` should be returned to the caller as-is.
+fn retry_delay(
+    policy: &RetryPolicy,
+    attempt: u32,
+    result: &Result<Response, Error>,
+) -> Option<std::time::Duration> {
+    if attempt >= policy.max_retries {
+        return None;
+    }
+    match result {
+        Ok(response) if is_transient_status(response.status()) => {
+            Some(retry_after(response.headers()).unwrap_or_else(|| policy.backoff(attempt)))
+        }
+        Err(Error::Reqwest(e)) if is_transient_reqwest_error(e) => Some(policy.backoff(attempt)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod retry_tests {
+    use super::*;
+
+    fn response_with(status: u16, headers: &[(&str, &str)]) -> Response {
+        let mut builder = http::Response::builder().status(status);
+        for (name, value) in headers {
+            builder = builder.header(*name, *value);
+        }
+        Response::from(builder.body(reqwest::Body::from(Vec::new())).unwrap())
+    }
+
+    #[test]
+    fn retry_after_parses_delta_seconds() {
+        let response = response_with(429, &[("retry-after", "120")]);
+        assert_eq!(
+            retry_after(response.headers()),
+            Some(std::time::Duration::from_secs(120))
+        );
+    }
+
+    #[test]
+    fn retry_after_parses_http_date_in_the_future() {
+        let future = std::time::SystemTime::now() + std::time::Duration::from_secs(60);
+        let response = response_with(429, &[("retry-after", &httpdate::fmt_http_date(future))]);
+        let delay = retry_after(response.headers()).expect("future date should yield a delay");
+        assert!(delay.as_secs() <= 60 && delay.as_secs() >= 58);
+    }
+
+    #[test]
+    fn retry_after_rejects_http_date_in_the_past() {
+        let past = std::time::SystemTime::now() - std::time::Duration::from_secs(60);
+        let response = response_with(429, &[("retry-after", &httpdate::fmt_http_date(past))]);
+        assert_eq!(retry_after(response.headers()), None);
+    }
+
+    #[test]
+    fn retry_after_is_none_without_a_header() {
+        let response = response_with(429, &[]);
+        assert_eq!(retry_after(response.headers()), None);
+    }
+
+    #[test]
+    fn retry_delay_none_once_max_retries_exhausted() {
+        let policy = RetryPolicy::new(1);
+        let result = Ok(response_with(503, &[]));
+        assert!(retry_delay(&policy, 1, &result).is_none());
+    }
+
+    #[test]
+    fn retry_delay_prefers_retry_after_over_backoff() {
+        let policy = RetryPolicy::new(3);
+        let result = Ok(response_with(429, &[("retry-after", "7")]));
+        assert_eq!(
+            retry_delay(&policy, 0, &result),
+            Some(std::time::Duration::from_secs(7))
+        );
+    }
+
+    #[test]
+    fn retry_delay_falls_back_to_backoff_without_retry_after() {
+        let policy = RetryPolicy::new(3).base_interval(std::time::Duration::from_millis(100));
+        let result = Ok(response_with(503, &[]));
+        let delay = retry_delay(&policy, 0, &result).expect("5xx should be retried");
+        assert!(delay >= std::time::Duration::from_millis(50));
+        assert!(delay <= std::time::Duration::from_millis(100));
+    }
+
+    #[test]
+    fn retry_delay_none_for_non_transient_status() {
+        let policy = RetryPolicy::new(3);
+        let result = Ok(response_with(404, &[]));
+        assert_eq!(retry_delay(&policy, 0, &result), None);
+    }
+
+    #[test]
+    fn backoff_is_capped_at_max_interval() {
+        let policy = RetryPolicy::new(10)
+            .base_interval(std::time::Duration::from_secs(1))
+            .max_interval(std::time::Duration::from_secs(5));
+        assert!(policy.backoff(10) <= std::time::Duration::from_secs(5));
+    }
+}
+
+/// A feature-agnostic builder for [`Client`], mirroring [`reqwest::ClientBuilder`] and
+/// [`reqwest_middleware::ClientBuilder`]. When the `middleware` feature is enabled, the
+/// initialiser stack is delegated to [`reqwest_middleware::ClientBuilder`]; otherwise it is
+/// applied by [`Client::request`] itself.
+pub struct ClientBuilder {
+    client: VanillaClient,
+    initialisers: Vec<Arc<dyn RequestInitialiser>>,
+}
+
+impl ClientBuilder {
+    pub fn new(client: VanillaClient) -> Self {
+        Self {
+            client,
+            initialisers: Vec::new(),
+        }
+    }
+
+    /// Registers a [`RequestInitialiser`] to run on every request produced by the built [`Client`].
+    pub fn with_init(mut self, initialiser: impl RequestInitialiser) -> Self {
+        self.initialisers.push(Arc::new(initialiser));
+        self
+    }
+
+    pub fn build(self) -> Client {
+        #[cfg(feature = "middleware")]
+        {
+            let builder = reqwest_middleware::ClientBuilder::new(self.client)
+                .with_init(InitAdapter(self.initialisers));
+            Client::Middleware(builder.build())
+        }
+        #[cfg(not(feature = "middleware"))]
+        {
+            Client::Vanilla(self.client, VanillaInit(Arc::new(self.initialisers)))
+        }
+    }
+}
+
+/// Bridges our feature-agnostic [`RequestInitialiser`]s to a single
+/// [`reqwest_middleware::RequestInitialiser`], running them all in order.
+///
+/// This is registered as exactly one `reqwest_middleware` initialiser rather than one per entry
+/// so it always sees the freshly built, body-free request that `reqwest_middleware` hands to the
+/// first initialiser in its own chain. That lets it take a cloneable fallback up front, before any
+/// of *our* initialisers have had a chance to attach a non-cloneable streaming body — if one of
+/// them misbehaves and returns the wrong [`RequestBuilder`] variant, we fall back to that instead
+/// of panicking, and the fallback is guaranteed to be available regardless of how many
+/// initialisers are registered or what they do to the request. The one exception is a request
+/// that already failed to build (e.g. an invalid URL): it cannot be cloned, so it is returned
+/// unchanged without running the initialisers, and reports its error when sent.
+#[cfg(feature = "middleware")]
+struct InitAdapter(Vec<Arc<dyn RequestInitialiser>>);
+
+#[cfg(feature = "middleware")]
+impl reqwest_middleware::RequestInitialiser for InitAdapter {
+    fn init(&self, req: reqwest_middleware::RequestBuilder) -> reqwest_middleware::RequestBuilder {
+        let Some(fallback) = req.try_clone() else {
+            return req;
+        };
+        let mut builder = RequestBuilder::Middleware(req, None);
+        for initialiser in &self.0 {
+            builder = initialiser.init(builder);
+        }
+        match builder {
+            RequestBuilder::Middleware(req, _) => req,
+            RequestBuilder::Vanilla(..) => fallback,
+        }
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum Error {
     /// There was an error running some middleware
@@ -88,16 +371,27 @@ impl Client {
     /// See [`VanillaClient::request`]
     pub fn request<U: IntoUrl>(&self, method: Method, url: U) -> RequestBuilder {
         match self {
-            Client::Vanilla(c) => RequestBuilder::Vanilla(c.request(method, url)),
+            Client::Vanilla(c, init) => {
+                let mut builder = RequestBuilder::Vanilla(
+                    self.clone(),
+                    c.request(method, url),
+                    http::Extensions::new(),
+                    None,
+                );
+                for initialiser in init.0.iter() {
+                    builder = initialiser.init(builder);
+                }
+                builder
+            }
             #[cfg(feature = "middleware")]
-            Client::Middleware(c) => RequestBuilder::Middleware(c.request(method, url)),
+            Client::Middleware(c) => RequestBuilder::Middleware(c.request(method, url), None),
         }
     }
 
     /// See [`VanillaClient::execute`]
     pub async fn execute(&self, req: Request) -> Result<Response, Error> {
         match self {
-            Client::Vanilla(c) => c.execute(req).await.map_err(Into::into),
+            Client::Vanilla(c, _) => c.execute(req).await.map_err(Into::into),
             #[cfg(feature = "middleware")]
             Client::Middleware(c) => {
                 let mut ext = http::Extensions::new();
@@ -116,7 +410,7 @@ impl Client {
         ext: &mut http::Extensions,
     ) -> Result<Response, Error> {
         match self {
-            Client::Vanilla(c) => c.execute(req).await.map_err(Into::into),
+            Client::Vanilla(c, _) => c.execute(req).await.map_err(Into::into),
             Client::Middleware(c) => c
                 .execute_with_extensions(req, ext)
                 .await
@@ -125,13 +419,54 @@ impl Client {
     }
 }
 
+#[cfg(feature = "tower")]
+impl Client {
+    /// Wraps this client in a [`tower::Service`], so it can be composed with `tower::Layer`s
+    /// (e.g. rate limiters, concurrency limits, load shedders) via `ServiceBuilder`.
+    pub fn into_service(self) -> ClientService {
+        ClientService(self)
+    }
+}
+
+/// A [`tower::Service`] wrapper around [`Client`], produced by [`Client::into_service`].
+#[cfg(feature = "tower")]
+#[derive(Clone, Debug)]
+pub struct ClientService(Client);
+
+#[cfg(feature = "tower")]
+impl tower::Service<Request> for ClientService {
+    type Response = Response;
+    type Error = Error;
+    type Future = std::pin::Pin<Box<dyn std::future::Future<Output = Result<Response, Error>> + Send>>;
+
+    fn poll_ready(
+        &mut self,
+        _cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Result<(), Self::Error>> {
+        std::task::Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request) -> Self::Future {
+        let client = self.0.clone();
+        Box::pin(async move { client.execute(req).await })
+    }
+}
+
 /// This is a wrapper around [`reqwest::RequestBuilder`] and [`reqwest_middleware::RequestBuilder`] exposing the same API.
 #[must_use = "RequestBuilder does nothing until you 'send' it"]
 #[derive(Debug)]
 pub enum RequestBuilder {
-    Vanilla(reqwest::RequestBuilder),
+    /// The inner builder, plus the [`Client`] it was created from, a side-channel set of
+    /// [`Extensions`](http::Extensions) (since [`reqwest::RequestBuilder`] has no concept of
+    /// extensions), and an optional [`RetryPolicy`].
+    Vanilla(
+        Client,
+        reqwest::RequestBuilder,
+        http::Extensions,
+        Option<RetryPolicy>,
+    ),
     #[cfg(feature = "middleware")]
-    Middleware(reqwest_middleware::RequestBuilder),
+    Middleware(reqwest_middleware::RequestBuilder, Option<RetryPolicy>),
 }
 
 impl RequestBuilder {
@@ -143,26 +478,38 @@ impl RequestBuilder {
         <HeaderValue as TryFrom<V>>::Error: Into<http::Error>,
     {
         match self {
-            RequestBuilder::Vanilla(c) => RequestBuilder::Vanilla(c.header(key, value)),
+            RequestBuilder::Vanilla(client, c, ext, retry) => {
+                RequestBuilder::Vanilla(client, c.header(key, value), ext, retry)
+            }
             #[cfg(feature = "middleware")]
-            RequestBuilder::Middleware(c) => RequestBuilder::Middleware(c.header(key, value)),
+            RequestBuilder::Middleware(c, retry) => {
+                RequestBuilder::Middleware(c.header(key, value), retry)
+            }
         }
     }
 
     pub fn headers(self, headers: HeaderMap) -> Self {
         match self {
-            RequestBuilder::Vanilla(c) => RequestBuilder::Vanilla(c.headers(headers)),
+            RequestBuilder::Vanilla(client, c, ext, retry) => {
+                RequestBuilder::Vanilla(client, c.headers(headers), ext, retry)
+            }
             #[cfg(feature = "middleware")]
-            RequestBuilder::Middleware(c) => RequestBuilder::Middleware(c.headers(headers)),
+            RequestBuilder::Middleware(c, retry) => {
+                RequestBuilder::Middleware(c.headers(headers), retry)
+            }
         }
     }
 
     #[cfg(not(target_arch = "wasm32"))]
     pub fn version(self, version: reqwest::Version) -> Self {
         match self {
-            RequestBuilder::Vanilla(c) => RequestBuilder::Vanilla(c.version(version)),
+            RequestBuilder::Vanilla(client, c, ext, retry) => {
+                RequestBuilder::Vanilla(client, c.version(version), ext, retry)
+            }
             #[cfg(feature = "middleware")]
-            RequestBuilder::Middleware(c) => RequestBuilder::Middleware(c.version(version)),
+            RequestBuilder::Middleware(c, retry) => {
+                RequestBuilder::Middleware(c.version(version), retry)
+            }
         }
     }
 
@@ -172,10 +519,12 @@ impl RequestBuilder {
         P: Display,
     {
         match self {
-            RequestBuilder::Vanilla(c) => RequestBuilder::Vanilla(c.basic_auth(username, password)),
+            RequestBuilder::Vanilla(client, c, ext, retry) => {
+                RequestBuilder::Vanilla(client, c.basic_auth(username, password), ext, retry)
+            }
             #[cfg(feature = "middleware")]
-            RequestBuilder::Middleware(c) => {
-                RequestBuilder::Middleware(c.basic_auth(username, password))
+            RequestBuilder::Middleware(c, retry) => {
+                RequestBuilder::Middleware(c.basic_auth(username, password), retry)
             }
         }
     }
@@ -185,96 +534,265 @@ impl RequestBuilder {
         T: Display,
     {
         match self {
-            RequestBuilder::Vanilla(c) => RequestBuilder::Vanilla(c.bearer_auth(token)),
+            RequestBuilder::Vanilla(client, c, ext, retry) => {
+                RequestBuilder::Vanilla(client, c.bearer_auth(token), ext, retry)
+            }
             #[cfg(feature = "middleware")]
-            RequestBuilder::Middleware(c) => RequestBuilder::Middleware(c.bearer_auth(token)),
+            RequestBuilder::Middleware(c, retry) => {
+                RequestBuilder::Middleware(c.bearer_auth(token), retry)
+            }
         }
     }
 
     pub fn body<T: Into<Body>>(self, body: T) -> Self {
         match self {
-            RequestBuilder::Vanilla(c) => RequestBuilder::Vanilla(c.body(body)),
+            RequestBuilder::Vanilla(client, c, ext, retry) => {
+                RequestBuilder::Vanilla(client, c.body(body), ext, retry)
+            }
             #[cfg(feature = "middleware")]
-            RequestBuilder::Middleware(c) => RequestBuilder::Middleware(c.body(body)),
+            RequestBuilder::Middleware(c, retry) => RequestBuilder::Middleware(c.body(body), retry),
         }
     }
 
     #[cfg(not(target_arch = "wasm32"))]
     pub fn timeout(self, timeout: std::time::Duration) -> Self {
         match self {
-            RequestBuilder::Vanilla(c) => RequestBuilder::Vanilla(c.timeout(timeout)),
+            RequestBuilder::Vanilla(client, c, ext, retry) => {
+                RequestBuilder::Vanilla(client, c.timeout(timeout), ext, retry)
+            }
             #[cfg(feature = "middleware")]
-            RequestBuilder::Middleware(c) => RequestBuilder::Middleware(c.timeout(timeout)),
+            RequestBuilder::Middleware(c, retry) => {
+                RequestBuilder::Middleware(c.timeout(timeout), retry)
+            }
+        }
+    }
+
+    /// Sets `Request.mode` to `no-cors` for the browser `fetch` call. See [`reqwest::RequestBuilder::fetch_mode_no_cors`].
+    ///
+    /// `reqwest_middleware::RequestBuilder` doesn't expose this reqwest wasm32 surface, so on the
+    /// `Middleware` variant this is a no-op; build with the `middleware` feature disabled if you
+    /// need this to take effect.
+    #[cfg(target_arch = "wasm32")]
+    #[cfg_attr(
+        feature = "middleware",
+        deprecated(
+            note = "no-op on the Middleware client variant: reqwest_middleware::RequestBuilder doesn't expose this wasm fetch-mode surface"
+        )
+    )]
+    pub fn fetch_mode_no_cors(self) -> Self {
+        match self {
+            RequestBuilder::Vanilla(client, c, ext, retry) => {
+                RequestBuilder::Vanilla(client, c.fetch_mode_no_cors(), ext, retry)
+            }
+            #[cfg(feature = "middleware")]
+            RequestBuilder::Middleware(c, retry) => RequestBuilder::Middleware(c, retry),
+        }
+    }
+
+    /// Sets `Request.credentials` to `include` for the browser `fetch` call.
+    ///
+    /// `reqwest_middleware::RequestBuilder` doesn't expose this reqwest wasm32 surface, so on the
+    /// `Middleware` variant this is a no-op; build with the `middleware` feature disabled if you
+    /// need this to take effect.
+    #[cfg(target_arch = "wasm32")]
+    #[cfg_attr(
+        feature = "middleware",
+        deprecated(
+            note = "no-op on the Middleware client variant: reqwest_middleware::RequestBuilder doesn't expose this wasm fetch-mode surface"
+        )
+    )]
+    pub fn fetch_credentials_include(self) -> Self {
+        match self {
+            RequestBuilder::Vanilla(client, c, ext, retry) => {
+                RequestBuilder::Vanilla(client, c.fetch_credentials_include(), ext, retry)
+            }
+            #[cfg(feature = "middleware")]
+            RequestBuilder::Middleware(c, retry) => RequestBuilder::Middleware(c, retry),
+        }
+    }
+
+    /// Sets `Request.credentials` to `same-origin` for the browser `fetch` call.
+    ///
+    /// `reqwest_middleware::RequestBuilder` doesn't expose this reqwest wasm32 surface, so on the
+    /// `Middleware` variant this is a no-op; build with the `middleware` feature disabled if you
+    /// need this to take effect.
+    #[cfg(target_arch = "wasm32")]
+    #[cfg_attr(
+        feature = "middleware",
+        deprecated(
+            note = "no-op on the Middleware client variant: reqwest_middleware::RequestBuilder doesn't expose this wasm fetch-mode surface"
+        )
+    )]
+    pub fn fetch_credentials_same_origin(self) -> Self {
+        match self {
+            RequestBuilder::Vanilla(client, c, ext, retry) => {
+                RequestBuilder::Vanilla(client, c.fetch_credentials_same_origin(), ext, retry)
+            }
+            #[cfg(feature = "middleware")]
+            RequestBuilder::Middleware(c, retry) => RequestBuilder::Middleware(c, retry),
+        }
+    }
+
+    /// Sets `Request.credentials` to `omit` for the browser `fetch` call.
+    ///
+    /// `reqwest_middleware::RequestBuilder` doesn't expose this reqwest wasm32 surface, so on the
+    /// `Middleware` variant this is a no-op; build with the `middleware` feature disabled if you
+    /// need this to take effect.
+    #[cfg(target_arch = "wasm32")]
+    #[cfg_attr(
+        feature = "middleware",
+        deprecated(
+            note = "no-op on the Middleware client variant: reqwest_middleware::RequestBuilder doesn't expose this wasm fetch-mode surface"
+        )
+    )]
+    pub fn fetch_credentials_omit(self) -> Self {
+        match self {
+            RequestBuilder::Vanilla(client, c, ext, retry) => {
+                RequestBuilder::Vanilla(client, c.fetch_credentials_omit(), ext, retry)
+            }
+            #[cfg(feature = "middleware")]
+            RequestBuilder::Middleware(c, retry) => RequestBuilder::Middleware(c, retry),
         }
     }
 
     #[cfg(feature = "multipart")]
     pub fn multipart(self, multipart: Form) -> Self {
         match self {
-            RequestBuilder::Vanilla(c) => RequestBuilder::Vanilla(c.multipart(multipart)),
+            RequestBuilder::Vanilla(client, c, ext, retry) => {
+                RequestBuilder::Vanilla(client, c.multipart(multipart), ext, retry)
+            }
             #[cfg(feature = "middleware")]
-            RequestBuilder::Middleware(c) => RequestBuilder::Middleware(c.multipart(multipart)),
+            RequestBuilder::Middleware(c, retry) => {
+                RequestBuilder::Middleware(c.multipart(multipart), retry)
+            }
         }
     }
 
     pub fn query<T: Serialize + ?Sized>(self, query: &T) -> Self {
         match self {
-            RequestBuilder::Vanilla(c) => RequestBuilder::Vanilla(c.query(query)),
+            RequestBuilder::Vanilla(client, c, ext, retry) => {
+                RequestBuilder::Vanilla(client, c.query(query), ext, retry)
+            }
             #[cfg(feature = "middleware")]
-            RequestBuilder::Middleware(c) => RequestBuilder::Middleware(c.query(query)),
+            RequestBuilder::Middleware(c, retry) => RequestBuilder::Middleware(c.query(query), retry),
         }
     }
 
     pub fn form<T: Serialize + ?Sized>(self, form: &T) -> Self {
         match self {
-            RequestBuilder::Vanilla(c) => RequestBuilder::Vanilla(c.form(form)),
+            RequestBuilder::Vanilla(client, c, ext, retry) => {
+                RequestBuilder::Vanilla(client, c.form(form), ext, retry)
+            }
             #[cfg(feature = "middleware")]
-            RequestBuilder::Middleware(c) => RequestBuilder::Middleware(c.form(form)),
+            RequestBuilder::Middleware(c, retry) => RequestBuilder::Middleware(c.form(form), retry),
         }
     }
 
     #[cfg(feature = "json")]
     pub fn json<T: Serialize + ?Sized>(self, json: &T) -> Self {
         match self {
-            RequestBuilder::Vanilla(c) => RequestBuilder::Vanilla(c.json(json)),
+            RequestBuilder::Vanilla(client, c, ext, retry) => {
+                RequestBuilder::Vanilla(client, c.json(json), ext, retry)
+            }
             #[cfg(feature = "middleware")]
-            RequestBuilder::Middleware(c) => RequestBuilder::Middleware(c.json(json)),
+            RequestBuilder::Middleware(c, retry) => RequestBuilder::Middleware(c.json(json), retry),
         }
     }
 
     pub fn build(self) -> reqwest::Result<Request> {
         match self {
-            RequestBuilder::Vanilla(c) => c.build(),
+            RequestBuilder::Vanilla(_, c, _, _) => c.build(),
             #[cfg(feature = "middleware")]
-            RequestBuilder::Middleware(c) => c.build(),
+            RequestBuilder::Middleware(c, _) => c.build(),
         }
     }
 
-    /// Inserts the extension into this request builder (if middleware)
-    #[cfg(feature = "middleware")]
+    /// Inserts the extension into this request builder.
     pub fn with_extension<T: Clone + Send + Sync + 'static>(self, extension: T) -> Self {
         match self {
-            RequestBuilder::Middleware(c) => {
-                RequestBuilder::Middleware(c.with_extension(extension))
+            RequestBuilder::Vanilla(client, c, mut ext, retry) => {
+                ext.insert(extension);
+                RequestBuilder::Vanilla(client, c, ext, retry)
+            }
+            #[cfg(feature = "middleware")]
+            RequestBuilder::Middleware(c, retry) => {
+                RequestBuilder::Middleware(c.with_extension(extension), retry)
             }
-            c => c,
         }
     }
 
-    /// Returns a mutable reference to the internal set of extensions for this request, or panics if not middleware
-    #[cfg(feature = "middleware")]
+    /// Returns a mutable reference to the internal set of extensions for this request.
     pub fn extensions(&mut self) -> &mut http::Extensions {
         match self {
-            RequestBuilder::Vanilla(_) => panic!("attempted to get extensions of vanilla client"),
-            RequestBuilder::Middleware(c) => c.extensions(),
+            RequestBuilder::Vanilla(_, _, ext, _) => ext,
+            #[cfg(feature = "middleware")]
+            RequestBuilder::Middleware(c, _) => c.extensions(),
+        }
+    }
+
+    /// Installs a [`RetryPolicy`] that retries this request on transient failures (connection
+    /// errors, timeouts, and 408/429/5xx responses) when [`send`](Self::send) is called. Works
+    /// regardless of the `middleware` feature.
+    pub fn with_retry(self, policy: RetryPolicy) -> Self {
+        match self {
+            RequestBuilder::Vanilla(client, c, ext, _) => {
+                RequestBuilder::Vanilla(client, c, ext, Some(policy))
+            }
+            #[cfg(feature = "middleware")]
+            RequestBuilder::Middleware(c, _) => RequestBuilder::Middleware(c, Some(policy)),
         }
     }
 
     pub async fn send(self) -> Result<Response, Error> {
         match self {
-            RequestBuilder::Vanilla(c) => c.send().await.map_err(Into::into),
+            RequestBuilder::Vanilla(client, mut builder, mut ext, retry) => {
+                let mut attempt = 0u32;
+                loop {
+                    let next = retry.is_some().then(|| builder.try_clone()).flatten();
+                    let req = builder.build()?;
+                    #[cfg(feature = "middleware")]
+                    let result = client.execute_with_extensions(req, &mut ext).await;
+                    #[cfg(not(feature = "middleware"))]
+                    let result = {
+                        let _ = &mut ext;
+                        client.execute(req).await
+                    };
+
+                    let Some(policy) = &retry else {
+                        return result;
+                    };
+                    let Some(delay) = retry_delay(policy, attempt, &result) else {
+                        return result;
+                    };
+                    let Some(next) = next else {
+                        return result;
+                    };
+                    sleep(delay).await;
+                    builder = next;
+                    attempt += 1;
+                }
+            }
             #[cfg(feature = "middleware")]
-            RequestBuilder::Middleware(c) => c.send().await.map_err(Into::into),
+            RequestBuilder::Middleware(mut builder, retry) => {
+                let mut attempt = 0u32;
+                loop {
+                    let next = retry.is_some().then(|| builder.try_clone()).flatten();
+                    let result = builder.send().await.map_err(Into::into);
+
+                    let Some(policy) = &retry else {
+                        return result;
+                    };
+                    let Some(delay) = retry_delay(policy, attempt, &result) else {
+                        return result;
+                    };
+                    let Some(next) = next else {
+                        return result;
+                    };
+                    sleep(delay).await;
+                    builder = next;
+                    attempt += 1;
+                }
+            }
         }
     }
 
@@ -287,9 +805,16 @@ impl RequestBuilder {
     /// Note that extensions are not preserved through cloning.
     pub fn try_clone(&self) -> Option<Self> {
         match self {
-            RequestBuilder::Vanilla(c) => Some(RequestBuilder::Vanilla(c.try_clone()?)),
+            RequestBuilder::Vanilla(client, c, _, retry) => Some(RequestBuilder::Vanilla(
+                client.clone(),
+                c.try_clone()?,
+                http::Extensions::new(),
+                *retry,
+            )),
             #[cfg(feature = "middleware")]
-            RequestBuilder::Middleware(c) => Some(RequestBuilder::Middleware(c.try_clone()?)),
+            RequestBuilder::Middleware(c, retry) => {
+                Some(RequestBuilder::Middleware(c.try_clone()?, *retry))
+            }
         }
     }
 }